@@ -0,0 +1,63 @@
+use std::env;
+
+// Centralizes the environment configuration that used to be read ad hoc from main(), so a
+// deployment can be configured (JWT settings, CORS origins, pool size, bind address) without
+// recompiling.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub database_pool_max_connections: u32,
+    pub jwt_secret: String,
+    pub jwt_expires_in: i64,
+    pub jwt_maxage: i64,
+    pub server_host: String,
+    pub server_port: u16,
+    pub allowed_origins: Vec<String>,
+}
+
+impl Config {
+    pub fn init() -> Result<Self, String> {
+        let database_url = required_env("DATABASE_URL")?;
+        let jwt_secret = required_env("JWT_SECRET")?;
+        let jwt_expires_in = parse_env("JWT_EXPIRES_IN")?;
+        let jwt_maxage = parse_env("JWT_MAXAGE")?;
+
+        let database_pool_max_connections = env::var("DATABASE_POOL_MAX_CONNECTIONS")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()
+            .map_err(|_| "DATABASE_POOL_MAX_CONNECTIONS must be a number".to_string())?;
+
+        let server_host = env::var("SERVER_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
+        let server_port = env::var("SERVER_PORT")
+            .unwrap_or_else(|_| "8000".to_string())
+            .parse()
+            .map_err(|_| "SERVER_PORT must be a valid port number".to_string())?;
+
+        let allowed_origins = required_env("CORS_ALLOWED_ORIGINS")?
+            .split(',')
+            .map(|origin| origin.trim().to_string())
+            .filter(|origin| !origin.is_empty())
+            .collect();
+
+        Ok(Config {
+            database_url,
+            database_pool_max_connections,
+            jwt_secret,
+            jwt_expires_in,
+            jwt_maxage,
+            server_host,
+            server_port,
+            allowed_origins,
+        })
+    }
+}
+
+fn required_env(key: &str) -> Result<String, String> {
+    env::var(key).map_err(|_| format!("{} must be set", key))
+}
+
+fn parse_env<T: std::str::FromStr>(key: &str) -> Result<T, String> {
+    required_env(key)?
+        .parse()
+        .map_err(|_| format!("{} must be a valid number", key))
+}
@@ -0,0 +1,66 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use thiserror::Error;
+
+// Central error type. Every fallible handler returns `crate::error::Result<T>` and uses `?` instead of
+// hand-rolling a `(StatusCode, Json<Value>)` tuple for each failure path.
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Conflict(String),
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("{0}")]
+    Unauthorized(String),
+
+    #[error("{0}")]
+    Forbidden(String),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+pub type Result<T> = std::result::Result<T, AppError>;
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            AppError::NotFound(message) => (StatusCode::NOT_FOUND, message.clone()),
+            AppError::Conflict(message) => (StatusCode::CONFLICT, message.clone()),
+            AppError::Validation(message) => (StatusCode::BAD_REQUEST, message.clone()),
+            AppError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message.clone()),
+            AppError::Forbidden(message) => (StatusCode::FORBIDDEN, message.clone()),
+            AppError::Database(err) => database_error_response(err),
+        };
+
+        let body = Json(json!({
+            "status": if status.is_client_error() { "fail" } else { "error" },
+            "message": message
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+// Postgres reports a unique-violation as SQLSTATE 23505; detecting it through the error code is
+// more reliable than substring-matching the driver's message text.
+fn database_error_response(err: &sqlx::Error) -> (StatusCode, String) {
+    if let Some(db_err) = err.as_database_error() {
+        if db_err.code().as_deref() == Some("23505") {
+            return (
+                StatusCode::CONFLICT,
+                "A record with that value already exists".to_string(),
+            );
+        }
+    }
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use axum_extra::extract::cookie::Cookie;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use serde_json::json;
+
+use crate::{
+    error::{AppError, Result},
+    jwt_auth::TokenClaims,
+    model::UserModel,
+    schema::{LoginUserSchema, RegisterUserSchema},
+    AppState,
+};
+
+/**
+ * POST: /api/auth/register
+ *
+ * register_user_handler: A handler function to create a new user account with a hashed password.
+ *
+ * @param State(data): Reference to the AppState of the application.
+ * @param Json(body) : JSON payload containing the new user's name, email and password.
+ *
+ * @return Result<impl IntoResponse> : Returns the created user as JSON, or an AppError.
+ */
+pub async fn register_user_handler(
+    State(data): State<Arc<AppState>>,
+    Json(body): Json<RegisterUserSchema>,
+) -> Result<impl IntoResponse> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hashed_password = Argon2::default()
+        .hash_password(body.password.as_bytes(), &salt)
+        .map_err(|e| AppError::Validation(format!("Error while hashing password: {}", e)))?
+        .to_string();
+
+    let user = sqlx::query_as!(
+        UserModel,
+        "INSERT INTO users (name, email, password) VALUES ($1, $2, $3) RETURNING *",
+        body.name.to_string(),
+        body.email.to_string().to_lowercase(),
+        hashed_password
+    )
+    .fetch_one(&data.db)
+    .await?;
+
+    let user_response = json!({
+        "status": "success",
+        "data": json!({
+            "user": user
+        })
+    });
+    Ok((StatusCode::CREATED, Json(user_response)))
+}
+
+/**
+ * POST: /api/auth/login
+ *
+ * login_user_handler: A handler function to verify credentials and issue a signed JWT cookie.
+ *
+ * @param State(data): Reference to the AppState of the application.
+ * @param Json(body) : JSON payload containing the user's email and password.
+ *
+ * @return Result<impl IntoResponse> : Returns a success status as JSON and sets the signed JWT as an HttpOnly session cookie, or an AppError.
+ */
+pub async fn login_user_handler(
+    State(data): State<Arc<AppState>>,
+    Json(body): Json<LoginUserSchema>,
+) -> Result<impl IntoResponse> {
+    let user = sqlx::query_as!(
+        UserModel,
+        "SELECT * FROM users WHERE email = $1",
+        body.email.to_string().to_lowercase()
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(invalid_credentials)?;
+
+    let parsed_hash = PasswordHash::new(&user.password).map_err(|_| invalid_credentials())?;
+    Argon2::default()
+        .verify_password(body.password.as_bytes(), &parsed_hash)
+        .map_err(|_| invalid_credentials())?;
+
+    let now = Utc::now();
+    let iat = now.timestamp() as usize;
+    let exp = (now + Duration::minutes(data.config.jwt_expires_in)).timestamp() as usize;
+    let claims = TokenClaims {
+        sub: user.id.to_string(),
+        iat,
+        exp,
+    };
+
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(data.config.jwt_secret.as_bytes()),
+    )
+    .map_err(|e| AppError::Unauthorized(format!("Error while signing token: {}", e)))?;
+
+    let cookie = Cookie::build("token", token)
+        .path("/")
+        .max_age(cookie::time::Duration::minutes(data.config.jwt_maxage))
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .finish();
+
+    // The token lives only in the HttpOnly cookie set below — echoing it in the JSON body too
+    // would let page JS read it, defeating the point of HttpOnly.
+    let mut response = Json(json!({
+        "status": "success"
+    }))
+    .into_response();
+    response.headers_mut().insert(
+        header::SET_COOKIE,
+        cookie
+            .to_string()
+            .parse()
+            .map_err(|_| AppError::Unauthorized("Failed to build session cookie".to_string()))?,
+    );
+    Ok(response)
+}
+
+fn invalid_credentials() -> AppError {
+    AppError::Unauthorized("Invalid email or password".to_string())
+}
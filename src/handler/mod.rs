@@ -0,0 +1,379 @@
+pub mod auth;
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{
+        Path,
+        Query,
+        State
+    },
+    http::StatusCode,
+    response::IntoResponse,
+    Json
+};
+
+use serde_json::json;
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::{
+    error::{AppError, Result},
+    jwt_auth::RequireUser,
+    model::NoteModel,
+    schema::{
+        CreateNoteSchema,
+        FilterOptions,
+        UpdateNoteSchema
+    },
+    AppState
+};
+
+// Explicit column list, rather than `SELECT *`, so the generated `search_vector` column used for
+// full-text search isn't pulled back on every query.
+const NOTE_COLUMNS: &str =
+    "id, title, content, category, published, user_id, created_at, updated_at";
+
+/**
+ *  GET: /api/notes
+ *
+ *  note_list_handler : A handler function to fetch notes list from the database.
+ *
+ *  @param opts       : Optional parameters. These contains the Query filter options.
+ *  @param State(data): Reference to the AppState of the application.
+ *
+ *  @return Result<impl IntoResponse> : Returns the note list as JSON, or an AppError.
+ */
+#[utoipa::path(
+    get,
+    path = "/api/notes",
+    params(FilterOptions),
+    responses(
+        (status = 200, description = "List of notes", body = [NoteModel])
+    ),
+    tag = "notes"
+)]
+pub async fn note_list_handler(
+    opts: Option<Query<FilterOptions>>,
+    State(data): State<Arc<AppState>>
+) -> Result<impl IntoResponse> {
+
+    // Gets the filter options
+    let Query(opts) = opts.unwrap_or_default();
+
+    let limit = opts.limit.unwrap_or(10) as i64;
+    let offset = ((opts.page.unwrap_or(1).max(1) - 1) * opts.limit.unwrap_or(10)) as i64;
+
+    // The WHERE clauses depend on which filters were supplied, so this can't be a single
+    // compile-time `query_as!` — build it up with QueryBuilder instead.
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(format!("SELECT {} FROM notes", NOTE_COLUMNS));
+    let mut has_filter = false;
+
+    if let Some(search) = &opts.search {
+        builder.push(" WHERE search_vector @@ websearch_to_tsquery('english', ");
+        builder.push_bind(search.clone());
+        builder.push(")");
+        has_filter = true;
+    }
+
+    if let Some(category) = &opts.category {
+        builder.push(if has_filter { " AND category = " } else { " WHERE category = " });
+        builder.push_bind(category.clone());
+        has_filter = true;
+    }
+
+    if let Some(published) = opts.published {
+        builder.push(if has_filter { " AND published = " } else { " WHERE published = " });
+        builder.push_bind(published);
+        has_filter = true;
+    }
+
+    if let Some(search) = &opts.search {
+        builder.push(" ORDER BY ts_rank(search_vector, websearch_to_tsquery('english', ");
+        builder.push_bind(search.clone());
+        builder.push(")) DESC");
+    } else {
+        builder.push(" ORDER BY id");
+    }
+
+    builder.push(" LIMIT ");
+    builder.push_bind(limit);
+    builder.push(" OFFSET ");
+    builder.push_bind(offset);
+
+    let notes = builder
+        .build_query_as::<NoteModel>()
+        .fetch_all(&data.db)
+        .await?;
+
+    // Success Response
+    let json_response = json!({
+        "status": "success",
+        "results": notes.len(),
+        "filters": opts,
+        "notes": notes
+    });
+    Ok(Json(json_response))
+}
+
+/**
+ * POST: /api/notes
+ *
+ * create_note_handler: A handler function to create a note in the database, owned by the
+ * authenticated user.
+ *
+ * @param req_user    : The authenticated user, resolved from the request's JWT.
+ * @param State(data) : Reference to the AppState of the application.
+ * @param Json(body)  : JSON payload of the request
+ *
+ * @return Result<impl IntoResponse> : Returns the created note as JSON, or an AppError.
+ */
+#[utoipa::path(
+    post,
+    path = "/api/notes",
+    request_body = CreateNoteSchema,
+    responses(
+        (status = 201, description = "Note created", body = NoteModel),
+        (status = 409, description = "Note with that title already exists")
+    ),
+    tag = "notes"
+)]
+pub async fn create_note_handler(
+    req_user: RequireUser,
+    State(data): State<Arc<AppState>>,
+    Json(body): Json<CreateNoteSchema>
+) -> Result<impl IntoResponse> {
+
+    // Query the DB to insert a row with NoteModel members and with title, content and catergory values
+    let note = sqlx::query_as!(
+        NoteModel,
+        "INSERT INTO notes (title, content, category, user_id) VALUES ($1, $2, $3, $4) \
+         RETURNING id, title, content, category, published, user_id, created_at, updated_at",
+        body.title.to_string(),
+        body.content.to_string(),
+        body.category.to_owned().unwrap_or("".to_string()),
+        req_user.user.id
+    )
+    .fetch_one(&data.db)
+    .await?;
+
+    // Offload indexing of the new note to the background worker instead of doing it inline here.
+    data.jobs
+        .enqueue("note_indexing", json!({ "note_id": note.id }))
+        .await?;
+
+    let note_response = json!({
+        "status": "success",
+        "data": json!({
+            "note": note
+        })
+    });
+    Ok((StatusCode::CREATED, Json(note_response)))
+}
+
+/**
+ * GET: api/notes/:id
+ *
+ * get_note_handler  : Handler function to fetch a note/row from the DB
+ *
+ * @param Path(id)   : The id parameter from the request url, expected to be Uuid. Path extractor extracts the 'id'.
+ * @param State(data): The reference to AppState of the Application
+ *
+ * @return Result<impl IntoResponse> : Returns the note as JSON, or an AppError.
+ */
+#[utoipa::path(
+    get,
+    path = "/api/notes/{id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Note id")
+    ),
+    responses(
+        (status = 200, description = "Note found", body = NoteModel),
+        (status = 404, description = "Note not found")
+    ),
+    tag = "notes"
+)]
+pub async fn get_note_handler(
+    Path(id): Path<uuid::Uuid>,
+    State(data): State<Arc<AppState>>
+) -> Result<impl IntoResponse> {
+
+    // Query the DB to fetch a single row
+    let note = sqlx::query_as!(
+        NoteModel,
+        "SELECT id, title, content, category, published, user_id, created_at, updated_at \
+         FROM notes WHERE id = $1",
+        id
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Note with ID: {} not found", id)))?;
+
+    let note_response = json!({
+        "status": "success",
+        "data": json!({
+            "note": note
+        })
+    });
+    Ok(Json(note_response))
+}
+
+/**
+ * PATCH: api/notes/:id
+ *
+ * edit_note_handler: Handler function to modify a note/row from the DB given by the id, only
+ * allowed when the authenticated user owns the note.
+ *
+ * @param Path(id)   : The id parameter from the request url, expected to be Uuid. Path extractor extracts the 'id'.
+ * @param req_user   : The authenticated user, resolved from the request's JWT.
+ * @param State(data): The reference to AppState of the Application
+ * @param Json(body) : JSON payload containing the updated values of the note fields
+ *
+ * @return Result<impl IntoResponse> : Returns the updated note as JSON, or an AppError.
+ */
+#[utoipa::path(
+    patch,
+    path = "/api/notes/{id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Note id")
+    ),
+    request_body = UpdateNoteSchema,
+    responses(
+        (status = 200, description = "Note updated", body = NoteModel),
+        (status = 404, description = "Note not found"),
+        (status = 403, description = "Not the note's owner")
+    ),
+    tag = "notes"
+)]
+pub async fn edit_note_handler(
+    Path(id): Path<uuid::Uuid>,
+    req_user: RequireUser,
+    State(data): State<Arc<AppState>>,
+    Json(body): Json<UpdateNoteSchema>
+) -> Result<impl IntoResponse> {
+
+    // Query and check if the row exists with the id in the DB
+    let note = sqlx::query_as!(
+        NoteModel,
+        "SELECT id, title, content, category, published, user_id, created_at, updated_at \
+         FROM notes WHERE id = $1",
+        id
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Note with ID: {} not found", id)))?;
+
+    // Only the note's owner may modify it
+    if note.user_id != Some(req_user.user.id) {
+        return Err(AppError::Forbidden(
+            "You do not have permission to modify this note".to_string()
+        ));
+    }
+
+    // Chrono for updating time to current
+    let now = chrono::Utc::now();
+
+    // Query to modify the row data
+    let note = sqlx::query_as!(
+        NoteModel,
+        "UPDATE notes SET title = $1, content = $2, category = $3, published = $4, updated_at = $5 \
+         WHERE id = $6 \
+         RETURNING id, title, content, category, published, user_id, created_at, updated_at",
+        body.title.to_owned().unwrap_or(note.title),
+        body.content.to_owned().unwrap_or(note.content),
+        body.category.to_owned().or(note.category),
+        body.published.or(note.published).unwrap_or(false),
+        now,
+        id
+    )
+    .fetch_one(&data.db)
+    .await?;
+
+    let note_response = json!({
+        "status": "success",
+        "data": json!({
+            "note": note
+        })
+    });
+    Ok(Json(note_response))
+}
+
+/**
+ * DELETE: api/notes/:id
+ *
+ * delete_note_handler: Handler function to delete a note/row from the DB given by the id, only
+ * allowed when the authenticated user owns the note.
+ *
+ * @param Path(id)   : The id parameter from the request url, expected to be Uuid. Path extractor extracts the 'id'.
+ * @param req_user   : The authenticated user, resolved from the request's JWT.
+ * @param State(data): The reference to AppState of the Application
+ *
+ * @return Result<impl IntoResponse> : Returns 204 No Content, or an AppError.
+ */
+#[utoipa::path(
+    delete,
+    path = "/api/notes/{id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Note id")
+    ),
+    responses(
+        (status = 204, description = "Note deleted"),
+        (status = 404, description = "Note not found"),
+        (status = 403, description = "Not the note's owner")
+    ),
+    tag = "notes"
+)]
+pub async fn delete_note_handler(
+    Path(id): Path<uuid::Uuid>,
+    req_user: RequireUser,
+    State(data): State<Arc<AppState>>
+) -> Result<impl IntoResponse> {
+
+    // Query and check if the row exists with the id in the DB, and that the caller owns it
+    let note = sqlx::query_as!(
+        NoteModel,
+        "SELECT id, title, content, category, published, user_id, created_at, updated_at \
+         FROM notes WHERE id = $1",
+        id
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Note with ID: {} not found", id)))?;
+
+    if note.user_id != Some(req_user.user.id) {
+        return Err(AppError::Forbidden(
+            "You do not have permission to delete this note".to_string()
+        ));
+    }
+
+    // Query to delete row
+    let rows_affected = sqlx::query!("DELETE FROM notes WHERE id = $1", id)
+        .execute(&data.db)
+        .await?
+        .rows_affected();
+
+    // Error: No rows found to delete
+    if rows_affected == 0 {
+        return Err(AppError::NotFound(format!("Note with ID: {} not found", id)));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Handler for basic endpoint: /api/healthchecker
+#[utoipa::path(
+    get,
+    path = "/api/healthchecker",
+    responses(
+        (status = 200, description = "API is healthy")
+    ),
+    tag = "health"
+)]
+pub async fn health_checker_handler() -> impl IntoResponse {
+    const MESSAGE: &str = "Simple CRUD API with Rust, SQLx, Postgres and Axum";
+
+    let json_response = json!({
+        "status": "success",
+        "message": MESSAGE
+    });
+
+    Json(json_response)
+}
@@ -0,0 +1,160 @@
+use serde_json::Value;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::error::Result;
+
+const STALE_HEARTBEAT_SECS: f64 = 300.0;
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1000);
+// Refreshed well under STALE_HEARTBEAT_SECS so a job that's merely slow keeps renewing its lease
+// instead of being mistaken for a crashed worker and re-queued mid-run.
+const HEARTBEAT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+// Handle stored in AppState so handlers can enqueue work without reaching for the raw pool.
+#[derive(Clone)]
+pub struct JobQueueHandle {
+    pool: Pool<Postgres>,
+}
+
+impl JobQueueHandle {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn enqueue(&self, queue: &str, payload: Value) -> Result<Uuid> {
+        let id = sqlx::query_scalar!(
+            "INSERT INTO job_queue (queue, job) VALUES ($1, $2) RETURNING id",
+            queue,
+            payload
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(id)
+    }
+}
+
+struct ClaimedJob {
+    id: Uuid,
+    queue: String,
+    job: Value,
+}
+
+// Spawned from main() as a background task. Polls job_queue, claims one job at a time with
+// `FOR UPDATE SKIP LOCKED` so multiple workers can run without claiming the same row, and sweeps
+// stale `running` jobs back to `new` so a crashed worker doesn't strand them forever.
+pub fn spawn_worker(pool: Pool<Postgres>) {
+    tokio::spawn(async move {
+        loop {
+            reset_stale_jobs(&pool).await;
+
+            match claim_job(&pool).await {
+                Ok(Some(job)) => {
+                    let job_id = job.id;
+                    let heartbeat_handle = spawn_heartbeat(pool.clone(), job_id);
+
+                    // Run the handler on its own task so a panic inside it can't take down the
+                    // worker loop; a dead task just means this job is retried later.
+                    let dispatch_handle = tokio::spawn(async move { dispatch(&job).await });
+                    let dispatch_result = dispatch_handle.await;
+
+                    heartbeat_handle.abort();
+
+                    match dispatch_result {
+                        Ok(()) => {
+                            if let Err(err) =
+                                sqlx::query!("DELETE FROM job_queue WHERE id = $1", job_id)
+                                    .execute(&pool)
+                                    .await
+                            {
+                                tracing::error!("Failed to remove completed job {}: {:?}", job_id, err);
+                            }
+                        }
+                        // Left as `running` with a stopped heartbeat, so reset_stale_jobs re-queues it.
+                        Err(join_err) => {
+                            tracing::error!("Job handler for job {} panicked: {:?}", job_id, join_err);
+                        }
+                    }
+                }
+                Ok(None) => tokio::time::sleep(POLL_INTERVAL).await,
+                Err(err) => {
+                    tracing::error!("Failed to claim a job from job_queue: {:?}", err);
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+            }
+        }
+    });
+}
+
+// Keeps a claimed job's heartbeat fresh while it's in flight. Aborted as soon as the job finishes
+// (or panics), so it never outlives the work it's tracking.
+fn spawn_heartbeat(pool: Pool<Postgres>, job_id: Uuid) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+            if let Err(err) =
+                sqlx::query!("UPDATE job_queue SET heartbeat = now() WHERE id = $1", job_id)
+                    .execute(&pool)
+                    .await
+            {
+                tracing::error!("Failed to refresh heartbeat for job {}: {:?}", job_id, err);
+            }
+        }
+    })
+}
+
+async fn reset_stale_jobs(pool: &Pool<Postgres>) {
+    let result = sqlx::query!(
+        "UPDATE job_queue SET status = 'new', heartbeat = NULL \
+         WHERE status = 'running' AND heartbeat < now() - make_interval(secs => $1)",
+        STALE_HEARTBEAT_SECS
+    )
+    .execute(pool)
+    .await;
+
+    if let Err(err) = result {
+        tracing::error!("Failed to reset stale jobs: {:?}", err);
+    }
+}
+
+async fn claim_job(pool: &Pool<Postgres>) -> Result<Option<ClaimedJob>> {
+    let row = sqlx::query!(
+        r#"
+        UPDATE job_queue
+        SET status = 'running', heartbeat = now()
+        WHERE id = (
+            SELECT id FROM job_queue
+            WHERE status = 'new'
+            ORDER BY created_at
+            FOR UPDATE SKIP LOCKED
+            LIMIT 1
+        )
+        RETURNING id, queue, job
+        "#
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|row| ClaimedJob {
+        id: row.id,
+        queue: row.queue,
+        job: row.job,
+    }))
+}
+
+// Dispatches a claimed job to its handler by queue name. Add a new arm here per queue (indexing,
+// webhook notifications, email, ...).
+async fn dispatch(job: &ClaimedJob) {
+    match job.queue.as_str() {
+        "note_indexing" => {
+            tracing::info!("Indexing note job {}: {}", job.id, job.job);
+        }
+        other => {
+            tracing::warn!(
+                "No handler registered for queue \"{}\" (job {})",
+                other,
+                job.id
+            );
+        }
+    }
+}
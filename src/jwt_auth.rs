@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts},
+    http::request::Parts,
+    RequestPartsExt,
+};
+use axum_extra::extract::cookie::CookieJar;
+use jsonwebtoken::{decode, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{error::AppError, model::UserModel, AppState};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenClaims {
+    pub sub: String,
+    pub iat: usize,
+    pub exp: usize,
+}
+
+/**
+ * RequireUser: An axum extractor that authenticates the request using the
+ * JWT stored in the "token" cookie or the Authorization: Bearer header,
+ * then loads the corresponding user from the database.
+ */
+pub struct RequireUser {
+    pub user: UserModel,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequireUser
+where
+    Arc<AppState>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let data = Arc::<AppState>::from_ref(state);
+
+        let cookie_token = parts
+            .extract::<CookieJar>()
+            .await
+            .ok()
+            .and_then(|jar| jar.get("token").map(|cookie| cookie.value().to_string()));
+
+        let header_token = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer ").map(String::from));
+
+        let token = cookie_token
+            .or(header_token)
+            .ok_or_else(|| unauthorized("You are not logged in, please provide a token"))?;
+
+        let claims = decode::<TokenClaims>(
+            &token,
+            &DecodingKey::from_secret(data.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| unauthorized("Invalid token"))?
+        .claims;
+
+        let user_id = Uuid::parse_str(&claims.sub).map_err(|_| unauthorized("Invalid token"))?;
+
+        let user = sqlx::query_as!(UserModel, "SELECT * FROM users WHERE id = $1", user_id)
+            .fetch_optional(&data.db)
+            .await?
+            .ok_or_else(|| unauthorized("The user belonging to this token no longer exists"))?;
+
+        Ok(RequireUser { user })
+    }
+}
+
+fn unauthorized(message: &str) -> AppError {
+    AppError::Unauthorized(message.to_string())
+}
@@ -1,10 +1,16 @@
+mod config;
+mod error;
 mod handler;
+mod jobs;
+mod jwt_auth;
 mod model;
+mod openapi;
 mod route;
 mod schema;
 
 use axum::http::{
     header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
+    HeaderName,
     HeaderValue,
     Method
 };
@@ -18,12 +24,24 @@ use sqlx::{
     Postgres
 };
 
+use config::Config;
+use jobs::JobQueueHandle;
 use route::create_router;
-use tower_http::cors::{CorsLayer};
+use tower::ServiceBuilder;
+use tower_http::{
+    compression::CompressionLayer,
+    cors::CorsLayer,
+    decompression::DecompressionLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
+};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
 // Struct containing DB Pool
 pub struct AppState {
     db: Pool<Postgres>,
+    config: Config,
+    jobs: JobQueueHandle,
 }
 
 // main()
@@ -31,35 +49,82 @@ pub struct AppState {
 async fn main() {
     dotenv().ok(); // Load the .env
 
-    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    // Structured logging: honours RUST_LOG, defaults to debug-level app/tower_http/axum spans.
+    tracing_subscriber::registry()
+        .with(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "rust_axum_postgres_api=debug,tower_http=debug,axum=debug".into()),
+        )
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let config = match Config::init() {
+        Ok(config) => config,
+        Err(err) => {
+            tracing::error!("Invalid configuration: {}", err);
+            std::process::exit(1);
+        }
+    };
 
     let pool = match PgPoolOptions::new()
-        .max_connections(10)
-        .connect(&database_url)
+        .max_connections(config.database_pool_max_connections)
+        .connect(&config.database_url)
         .await
     {
         Ok(pool) => {
-            println!("✅Connection to the database is successful!");
+            tracing::info!("Connection to the database is successful!");
             pool
         }
         Err(err) => {
-            println!("❌ Failed to connect to the database: {:?}", err);
+            tracing::error!("Failed to connect to the database: {:?}", err);
             std::process::exit(1);
         }
     };
 
     // CORS Middleware
+    let allowed_origins: Vec<HeaderValue> = config
+        .allowed_origins
+        .iter()
+        .map(|origin| {
+            origin
+                .parse::<HeaderValue>()
+                .unwrap_or_else(|_| panic!("invalid CORS origin: {}", origin))
+        })
+        .collect();
+
     let cors = CorsLayer::new()
-    .allow_origin("http://localhost:3000".parse::<HeaderValue>().unwrap())
+    .allow_origin(allowed_origins)
     .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE])
     .allow_credentials(true)
     .allow_headers([AUTHORIZATION, ACCEPT, CONTENT_TYPE]);
 
-    // Create the Router and add the CORS layer
-    let app = create_router(Arc::new(AppState { db: pool.clone() })).layer(cors);
+    // Background job queue worker
+    jobs::spawn_worker(pool.clone());
+
+    let bind_address = format!("{}:{}", config.server_host, config.server_port);
+
+    let x_request_id = HeaderName::from_static("x-request-id");
+
+    // Create the Router. Request id and tracing go outermost so every request (including
+    // rejected ones) gets a span and a correlatable id; compression sits innermost, closest to
+    // the response body.
+    let app = create_router(Arc::new(AppState {
+        db: pool.clone(),
+        jobs: JobQueueHandle::new(pool.clone()),
+        config,
+    }))
+    .layer(
+        ServiceBuilder::new()
+            .layer(SetRequestIdLayer::new(x_request_id.clone(), MakeRequestUuid))
+            .layer(TraceLayer::new_for_http())
+            .layer(PropagateRequestIdLayer::new(x_request_id))
+            .layer(cors)
+            .layer(CompressionLayer::new())
+            .layer(DecompressionLayer::new()),
+    );
 
-    println!("🚀 Server started successfully at 127.0.0.1:8000");
-    axum::Server::bind(&"127.0.0.1:8000".parse().unwrap())
+    tracing::info!("Server started successfully at {}", bind_address);
+    axum::Server::bind(&bind_address.parse().unwrap())
         .serve(app.into_make_service())
         .await
         .unwrap();
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow, ToSchema)]
+pub struct NoteModel {
+    pub id: Uuid,
+    pub title: String,
+    pub content: String,
+    pub category: Option<String>,
+    pub published: Option<bool>,
+    pub user_id: Option<Uuid>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
+pub struct UserModel {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+    #[serde(skip_serializing)]
+    pub password: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,36 @@
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::{
+    handler::{
+        create_note_handler, delete_note_handler, edit_note_handler, get_note_handler,
+        health_checker_handler, note_list_handler,
+    },
+    model::NoteModel,
+    schema::{CreateNoteSchema, UpdateNoteSchema},
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        health_checker_handler,
+        note_list_handler,
+        create_note_handler,
+        get_note_handler,
+        edit_note_handler,
+        delete_note_handler,
+    ),
+    // FilterOptions is surfaced as query params via `params(FilterOptions)` on
+    // note_list_handler, not as a component schema — it only derives IntoParams, not ToSchema.
+    components(schemas(NoteModel, CreateNoteSchema, UpdateNoteSchema)),
+    tags(
+        (name = "notes", description = "Notes CRUD API"),
+        (name = "health", description = "Service health check")
+    )
+)]
+pub struct ApiDoc;
+
+// Serves the generated spec at /api/openapi.json and an interactive explorer at /docs.
+pub fn swagger_ui() -> SwaggerUi {
+    SwaggerUi::new("/docs").url("/api/openapi.json", ApiDoc::openapi())
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use axum::{routing::get, routing::post, Router};
+
+use crate::{
+    handler::{
+        auth::{login_user_handler, register_user_handler},
+        create_note_handler, delete_note_handler, edit_note_handler, get_note_handler,
+        health_checker_handler, note_list_handler,
+    },
+    openapi::swagger_ui,
+    AppState,
+};
+
+pub fn create_router(app_state: Arc<AppState>) -> Router {
+    Router::new()
+        .merge(swagger_ui())
+        .route("/api/healthchecker", get(health_checker_handler))
+        .route("/api/auth/register", post(register_user_handler))
+        .route("/api/auth/login", post(login_user_handler))
+        .route("/api/notes", get(note_list_handler).post(create_note_handler))
+        .route(
+            "/api/notes/:id",
+            get(get_note_handler)
+                .patch(edit_note_handler)
+                .delete(delete_note_handler),
+        )
+        .with_state(app_state)
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, IntoParams)]
+pub struct FilterOptions {
+    pub page: Option<usize>,
+    pub limit: Option<usize>,
+    pub search: Option<String>,
+    pub category: Option<String>,
+    pub published: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ParamOptions {
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct CreateNoteSchema {
+    pub title: String,
+    pub content: String,
+    pub category: Option<String>,
+    pub published: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, ToSchema)]
+pub struct UpdateNoteSchema {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub category: Option<String>,
+    pub published: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RegisterUserSchema {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LoginUserSchema {
+    pub email: String,
+    pub password: String,
+}